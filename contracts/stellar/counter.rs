@@ -1,4 +1,5 @@
 // Sample Stellar contract for testing multi-contract matrix
+#[derive(Default)]
 pub struct Counter {
     value: i32,
 }
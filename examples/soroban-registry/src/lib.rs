@@ -0,0 +1,26 @@
+//! Registry contract: the matrix's actual cross-contract caller. It holds
+//! no storage of its own — every entrypoint forwards into a `Counter`
+//! contract elsewhere in the matrix, which is what exercises UATU's
+//! call-graph linking and taint-propagation code path.
+#![no_std]
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol};
+
+use soroban_basic_fixture::CounterClient;
+
+#[contract]
+pub struct Registry;
+
+#[contractimpl]
+impl Registry {
+    /// Bumps a known `Counter` contract via its generated client.
+    pub fn bump(env: Env, counter: Address, who: Address, by: i32) -> i32 {
+        CounterClient::new(&env, &counter).inc(&who, &by)
+    }
+
+    /// Bumps a `Counter` contract whose id is only known at call time, via
+    /// a dynamic cross-contract invocation rather than a generated client.
+    pub fn bump_dynamic(env: Env, counter: Address, who: Address, by: i32) -> i32 {
+        let args = vec![&env, who.into_val(&env), by.into_val(&env)];
+        env.invoke_contract(&counter, &Symbol::new(&env, "inc"), args)
+    }
+}
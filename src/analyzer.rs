@@ -0,0 +1,50 @@
+//! Ties source scanning and rules together into the analysis pass that
+//! runs across the whole contract matrix.
+
+use std::path::Path;
+
+use crate::callgraph::{self, CallGraph};
+use crate::model::Finding;
+use crate::rules::all_rules;
+use crate::scan::ScannedFile;
+
+/// Run every registered rule over every file in `paths` and return all
+/// findings, in the order the files were given.
+pub fn analyze(paths: &[impl AsRef<Path>]) -> std::io::Result<Vec<Finding>> {
+    let files = read_all(paths)?;
+    Ok(run_rules(&files))
+}
+
+/// Everything [`analyze`] produces, plus the cross-contract call graph
+/// linking the matrix together.
+pub struct WorkspaceReport {
+    pub findings: Vec<Finding>,
+    pub call_graph: CallGraph,
+}
+
+/// Like [`analyze`], but also links the contracts in `paths` into a call
+/// graph and propagates existing findings across it: a caller that can
+/// reach a flagged callee gets its own finding pointing at the taint path.
+pub fn analyze_workspace(paths: &[impl AsRef<Path>]) -> std::io::Result<WorkspaceReport> {
+    let files = read_all(paths)?;
+    let mut findings = run_rules(&files);
+    let call_graph = callgraph::build(&files);
+    findings.extend(callgraph::taint_from_flagged(&call_graph, &files, &findings));
+    findings.extend(call_graph.ambiguous_findings());
+    Ok(WorkspaceReport { findings, call_graph })
+}
+
+fn read_all(paths: &[impl AsRef<Path>]) -> std::io::Result<Vec<ScannedFile>> {
+    paths.iter().map(|p| ScannedFile::read(p.as_ref())).collect()
+}
+
+fn run_rules(files: &[ScannedFile]) -> Vec<Finding> {
+    let rules = all_rules();
+    let mut findings = Vec::new();
+    for file in files {
+        for rule in &rules {
+            findings.extend(rule.check(file));
+        }
+    }
+    findings
+}
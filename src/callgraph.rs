@@ -0,0 +1,505 @@
+//! Cross-contract call graph: the fixtures are a "multi-contract matrix",
+//! so this links invocations found in one file to public functions
+//! defined in another, instead of analyzing each contract in isolation.
+//! The resulting graph also lets findings on a callee propagate to every
+//! caller that can reach it, across file boundaries.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use crate::model::{Finding, Severity, Span};
+use crate::scan::{find_impl_blocks, find_mod_blocks, match_paren, ScannedFile};
+
+/// A contract method or module function. Keyed by its defining file as
+/// well as its container/function name: two different files are free to
+/// declare a same-named type (the fixtures already do — there are two
+/// unrelated `Counter`s), and those must stay distinct nodes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId {
+    pub file: PathBuf,
+    pub container: String,
+    pub function: String,
+}
+
+impl NodeId {
+    fn new(file: PathBuf, container: impl Into<String>, function: impl Into<String>) -> Self {
+        NodeId {
+            file,
+            container: container.into(),
+            function: function.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for NodeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}::{} ({})", self.container, self.function, self.file.display())
+    }
+}
+
+/// Where a node is defined, so spans and reports can point back at it.
+#[derive(Debug, Clone)]
+pub struct NodeInfo {
+    body_start: usize,
+    body_end: usize,
+}
+
+/// A call site whose target name matched more than one node in the
+/// matrix, so it could not be resolved to a single callee without
+/// guessing. Reported as its own finding rather than picked arbitrarily.
+#[derive(Debug, Clone)]
+pub struct AmbiguousCall {
+    pub caller: NodeId,
+    pub target_function: String,
+    pub candidates: Vec<NodeId>,
+    pub line: usize,
+}
+
+/// Caller -> callee edges across the whole audited matrix.
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    pub nodes: HashMap<NodeId, NodeInfo>,
+    pub edges: Vec<(NodeId, NodeId)>,
+    pub ambiguous_calls: Vec<AmbiguousCall>,
+}
+
+impl CallGraph {
+    /// Render as a flat, line-oriented edge list (`caller -> callee`) the
+    /// audit report can embed directly — stable and diff-friendly, with no
+    /// need to pull in a JSON/serde dependency just to serialize it.
+    pub fn to_edge_list(&self) -> Vec<String> {
+        self.edges
+            .iter()
+            .map(|(from, to)| format!("{from} -> {to}"))
+            .collect()
+    }
+
+    /// Every node transitively reachable from `start` by following edges
+    /// (not including `start` itself).
+    pub fn reachable_from(&self, start: &NodeId) -> HashSet<NodeId> {
+        let mut seen = HashSet::new();
+        let mut stack = vec![start.clone()];
+        while let Some(node) = stack.pop() {
+            if !seen.insert(node.clone()) {
+                continue;
+            }
+            for (from, to) in &self.edges {
+                if from == &node && !seen.contains(to) {
+                    stack.push(to.clone());
+                }
+            }
+        }
+        seen.remove(start);
+        seen
+    }
+
+    /// One finding per unresolved ambiguous call site, so a call whose
+    /// target name matches several contracts is surfaced to the
+    /// maintainer instead of silently (and non-deterministically)
+    /// resolving to one of them.
+    pub fn ambiguous_findings(&self) -> Vec<Finding> {
+        self.ambiguous_calls
+            .iter()
+            .map(|call| {
+                let candidates = call
+                    .candidates
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Finding {
+                    rule: "cross_contract_ambiguous_callee",
+                    severity: Severity::Warning,
+                    message: format!(
+                        "`{}` calls `{}`, which matches more than one node in the matrix ({}); \
+                         cannot resolve the callee unambiguously — qualify the call or rename \
+                         one of the candidates",
+                        call.caller, call.target_function, candidates,
+                    ),
+                    span: Span {
+                        file: call.caller.file.clone(),
+                        line: call.line,
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Build the call graph for every file in `files`: nodes are every public
+/// contract method and module function; edges are `env.invoke_contract`
+/// calls, generated `*Client` calls, and direct `container::function`
+/// calls that resolve to exactly one node in the matrix.
+pub fn build(files: &[ScannedFile]) -> CallGraph {
+    let mut graph = CallGraph::default();
+
+    // First pass: collect every node, so a call site can resolve against a
+    // container defined in a different file than the one it's called from.
+    for file in files {
+        for block in find_impl_blocks(&file.text) {
+            for method in &block.methods {
+                graph.nodes.insert(
+                    NodeId::new(file.path.clone(), &block.type_name, &method.name),
+                    NodeInfo {
+                        body_start: method.body_start,
+                        body_end: method.body_start + method.body.len().saturating_sub(1),
+                    },
+                );
+            }
+        }
+        for module in find_mod_blocks(&file.text) {
+            for function in &module.functions {
+                graph.nodes.insert(
+                    NodeId::new(file.path.clone(), &module.name, &function.name),
+                    NodeInfo {
+                        body_start: function.body_start,
+                        body_end: function.body_start + function.body.len().saturating_sub(1),
+                    },
+                );
+            }
+        }
+    }
+
+    // Second pass: scan every node's body for call sites into another node.
+    for file in files {
+        for block in find_impl_blocks(&file.text) {
+            for method in &block.methods {
+                let caller = NodeId::new(file.path.clone(), &block.type_name, &method.name);
+                link_call_sites(&mut graph, file, &caller, &method.body);
+            }
+        }
+        for module in find_mod_blocks(&file.text) {
+            for function in &module.functions {
+                let caller = NodeId::new(file.path.clone(), &module.name, &function.name);
+                link_call_sites(&mut graph, file, &caller, &function.body);
+            }
+        }
+    }
+
+    graph
+}
+
+fn link_call_sites(graph: &mut CallGraph, file: &ScannedFile, caller: &NodeId, body: &str) {
+    for site in call_sites(body) {
+        match resolve_callee(graph, &site) {
+            Resolution::Unique(callee) => {
+                if &callee != caller {
+                    graph.edges.push((caller.clone(), callee));
+                }
+            }
+            Resolution::Ambiguous(candidates) => {
+                graph.ambiguous_calls.push(AmbiguousCall {
+                    caller: caller.clone(),
+                    target_function: site.function().to_string(),
+                    line: file.line_at(caller_offset(caller, graph) + site.offset()),
+                    candidates,
+                });
+            }
+            Resolution::None => {}
+        }
+    }
+}
+
+/// Byte offset of `caller`'s body start, used to translate a call site's
+/// in-body offset into a file-relative one for [`ScannedFile::line_at`].
+fn caller_offset(caller: &NodeId, graph: &CallGraph) -> usize {
+    graph.nodes.get(caller).map(|info| info.body_start).unwrap_or(0)
+}
+
+enum CallSite {
+    /// `Container::function(...)`, or `ContainerClient::new(...).function(...)`.
+    Qualified {
+        container: String,
+        function: String,
+        offset: usize,
+    },
+    /// `env.invoke_contract(&id, &Symbol::new(&env, "function"), ...)` —
+    /// the callee's container is only known at runtime (a dynamic contract
+    /// id), so these resolve by function name alone.
+    BareFunctionName { function: String, offset: usize },
+}
+
+impl CallSite {
+    fn function(&self) -> &str {
+        match self {
+            CallSite::Qualified { function, .. } => function,
+            CallSite::BareFunctionName { function, .. } => function,
+        }
+    }
+
+    fn offset(&self) -> usize {
+        match self {
+            CallSite::Qualified { offset, .. } => *offset,
+            CallSite::BareFunctionName { offset, .. } => *offset,
+        }
+    }
+}
+
+fn call_sites(body: &str) -> Vec<CallSite> {
+    let mut sites = qualified_calls(body);
+    sites.extend(invoke_contract_calls(body));
+    sites
+}
+
+fn qualified_calls(body: &str) -> Vec<CallSite> {
+    let mut sites = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = body[search_from..].find("::") {
+        let sep_idx = search_from + rel;
+        let container_start = body[..sep_idx]
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let container = &body[container_start..sep_idx];
+        let fn_start = sep_idx + 2;
+        let fn_end = body[fn_start..]
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map(|i| fn_start + i)
+            .unwrap_or(body.len());
+        let function = &body[fn_start..fn_end];
+        search_from = fn_end.max(sep_idx + 2);
+
+        if container.is_empty() || function.is_empty() || body.as_bytes().get(fn_end) != Some(&b'(') {
+            continue;
+        }
+        if let Some(base) = container.strip_suffix("Client") {
+            if function == "new" {
+                if let Some(chained) = chained_method_after(body, fn_end) {
+                    sites.push(CallSite::Qualified {
+                        container: base.to_string(),
+                        function: chained,
+                        offset: container_start,
+                    });
+                }
+                continue;
+            }
+        }
+        sites.push(CallSite::Qualified {
+            container: container.to_string(),
+            function: function.to_string(),
+            offset: container_start,
+        });
+    }
+    sites
+}
+
+/// Given the index of the `(` opening `...Client::new(...)`, find the
+/// method chained directly onto it: `...new(&env, &id).inc(&who, &5)`.
+fn chained_method_after(body: &str, paren_idx: usize) -> Option<String> {
+    let end = match_paren(body, paren_idx)?;
+    let rest = body[end + 1..].strip_prefix('.')?;
+    let name_end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+    (rest.as_bytes().get(name_end) == Some(&b'(')).then(|| rest[..name_end].to_string())
+}
+
+fn invoke_contract_calls(body: &str) -> Vec<CallSite> {
+    let marker = ".invoke_contract(";
+    let mut sites = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = body[search_from..].find(marker) {
+        let site_offset = search_from + rel;
+        let paren_idx = site_offset + marker.len() - 1;
+        search_from = paren_idx + 1;
+        let Some(end) = match_paren(body, paren_idx) else {
+            continue;
+        };
+        if let Some(name) = extract_quoted(&body[paren_idx + 1..end]) {
+            sites.push(CallSite::BareFunctionName {
+                function: name,
+                offset: site_offset,
+            });
+        }
+    }
+    sites
+}
+
+/// The first `"..."` string literal in `s`.
+fn extract_quoted(s: &str) -> Option<String> {
+    let start = s.find('"')? + 1;
+    let end = start + s[start..].find('"')?;
+    Some(s[start..end].to_string())
+}
+
+enum Resolution {
+    Unique(NodeId),
+    Ambiguous(Vec<NodeId>),
+    None,
+}
+
+fn resolve_callee(graph: &CallGraph, site: &CallSite) -> Resolution {
+    let candidates: Vec<NodeId> = match site {
+        CallSite::Qualified { container, function, .. } => graph
+            .nodes
+            .keys()
+            .filter(|n| &n.container == container && &n.function == function)
+            .cloned()
+            .collect(),
+        CallSite::BareFunctionName { function, .. } => graph
+            .nodes
+            .keys()
+            .filter(|n| &n.function == function)
+            .cloned()
+            .collect(),
+    };
+    match candidates.len() {
+        0 => Resolution::None,
+        1 => Resolution::Unique(candidates.into_iter().next().expect("len checked above")),
+        _ => Resolution::Ambiguous(candidates),
+    }
+}
+
+/// Which node (if any) a finding's span falls inside — needed to turn raw
+/// per-file findings into per-node taint for [`taint_from_flagged`].
+fn node_for_span(graph: &CallGraph, files: &[ScannedFile], finding: &Finding) -> Option<NodeId> {
+    let file = files.iter().find(|f| f.path == finding.span.file)?;
+    graph.nodes.iter().find_map(|(id, info)| {
+        if id.file != finding.span.file {
+            return None;
+        }
+        let start_line = file.line_at(info.body_start);
+        let end_line = file.line_at(info.body_end);
+        (start_line..=end_line)
+            .contains(&finding.span.line)
+            .then(|| id.clone())
+    })
+}
+
+/// For every node that can reach (transitively) a callee with existing
+/// findings against it, emit a finding on the reaching node — propagating
+/// taint across the matrix instead of leaving each contract's findings
+/// siloed to the file they were raised in.
+pub fn taint_from_flagged(
+    graph: &CallGraph,
+    files: &[ScannedFile],
+    findings: &[Finding],
+) -> Vec<Finding> {
+    let flagged: HashSet<NodeId> = findings
+        .iter()
+        .filter_map(|f| node_for_span(graph, files, f))
+        .collect();
+    if flagged.is_empty() {
+        return Vec::new();
+    }
+
+    let mut out = Vec::new();
+    for (caller, info) in &graph.nodes {
+        for callee in graph.reachable_from(caller) {
+            if !flagged.contains(&callee) {
+                continue;
+            }
+            let Some(file) = files.iter().find(|f| f.path == caller.file) else {
+                continue;
+            };
+            out.push(Finding {
+                rule: "cross_contract_taint",
+                severity: Severity::Warning,
+                message: format!(
+                    "`{caller}` calls into `{callee}`, which has existing audit findings \
+                     against it — the issue is reachable from this entrypoint too"
+                ),
+                span: Span {
+                    file: caller.file.clone(),
+                    line: file.line_at(info.body_start),
+                },
+            });
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(path: &str, text: &str) -> ScannedFile {
+        ScannedFile {
+            path: path.into(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn same_named_type_and_method_in_different_files_stay_distinct_nodes() {
+        let files = [
+            scan("a/counter.rs", "impl Counter {\n    pub fn get() -> i32 { 1 }\n}\n"),
+            scan("b/counter.rs", "impl Counter {\n    pub fn get() -> i32 { 2 }\n}\n"),
+        ];
+        let graph = build(&files);
+        assert_eq!(graph.nodes.len(), 2);
+    }
+
+    #[test]
+    fn qualified_call_resolves_uniquely_and_creates_an_edge() {
+        let files = [
+            scan(
+                "registry.rs",
+                "impl Registry {\n    pub fn proxy() -> i32 {\n        Counter::get()\n    }\n}\n",
+            ),
+            scan("counter.rs", "impl Counter {\n    pub fn get() -> i32 {\n        42\n    }\n}\n"),
+        ];
+        let graph = build(&files);
+        assert_eq!(graph.edges.len(), 1);
+        assert!(graph.ambiguous_calls.is_empty());
+        let (caller, callee) = &graph.edges[0];
+        assert_eq!(caller.container, "Registry");
+        assert_eq!(callee.container, "Counter");
+        assert_eq!(callee.file, PathBuf::from("counter.rs"));
+    }
+
+    #[test]
+    fn invoke_contract_resolves_by_function_name_when_only_one_candidate_exists() {
+        let files = [
+            scan(
+                "registry.rs",
+                "impl Registry {\n    pub fn proxy(env: Env, id: Address) -> i32 {\n        \
+                 env.invoke_contract(&id, &Symbol::new(&env, \"get\"), args)\n    }\n}\n",
+            ),
+            scan("counter.rs", "impl Counter {\n    pub fn get() -> i32 {\n        42\n    }\n}\n"),
+        ];
+        let graph = build(&files);
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!(graph.edges[0].1.function, "get");
+    }
+
+    #[test]
+    fn qualified_call_matching_more_than_one_candidate_is_reported_ambiguous_not_guessed() {
+        let files = [
+            scan(
+                "registry.rs",
+                "impl Registry {\n    pub fn proxy() -> i32 {\n        Counter::get()\n    }\n}\n",
+            ),
+            scan("a/counter.rs", "impl Counter {\n    pub fn get() -> i32 { 1 }\n}\n"),
+            scan("b/counter.rs", "impl Counter {\n    pub fn get() -> i32 { 2 }\n}\n"),
+        ];
+        let graph = build(&files);
+        assert!(graph.edges.is_empty());
+        assert_eq!(graph.ambiguous_calls.len(), 1);
+        assert_eq!(graph.ambiguous_calls[0].candidates.len(), 2);
+        assert_eq!(graph.ambiguous_findings().len(), 1);
+    }
+
+    #[test]
+    fn taint_propagates_from_a_flagged_callee_to_its_caller() {
+        let files = [
+            scan(
+                "registry.rs",
+                "impl Registry {\n    pub fn proxy() -> i32 {\n        Counter::get()\n    }\n}\n",
+            ),
+            scan("counter.rs", "impl Counter {\n    pub fn get() -> i32 {\n        42\n    }\n}\n"),
+        ];
+        let graph = build(&files);
+        let findings = vec![Finding {
+            rule: "some_rule",
+            severity: Severity::Critical,
+            message: "bug in get".to_string(),
+            span: Span {
+                file: PathBuf::from("counter.rs"),
+                line: 3,
+            },
+        }];
+        let tainted = taint_from_flagged(&graph, &files, &findings);
+        assert_eq!(tainted.len(), 1);
+        assert_eq!(tainted[0].span.file, PathBuf::from("registry.rs"));
+        assert_eq!(tainted[0].rule, "cross_contract_taint");
+    }
+}
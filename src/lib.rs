@@ -0,0 +1,8 @@
+//! UATU's contract audit engine: source scanning, rules, and the analysis
+//! pass that runs them across the multi-contract fixture matrix.
+
+pub mod analyzer;
+pub mod callgraph;
+pub mod model;
+pub mod rules;
+pub mod scan;
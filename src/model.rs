@@ -0,0 +1,40 @@
+//! Core types shared by every audit rule.
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// How serious a finding is. Ordered so reports can sort worst-first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Informational,
+    Warning,
+    Critical,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Severity::Informational => "informational",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A location inside the audited source tree, used to point maintainers at
+/// the exact line a finding was raised for.
+#[derive(Debug, Clone)]
+pub struct Span {
+    pub file: PathBuf,
+    pub line: usize,
+}
+
+/// A single issue raised by a rule.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span,
+}
@@ -0,0 +1,173 @@
+//! Flags public contract methods that mutate storage but never publish an
+//! event, leaving indexers and other off-chain observers with no way to
+//! see the state change happened.
+
+use crate::model::{Finding, Severity, Span};
+use crate::scan::{find_attr_impl_blocks, find_storage_writes, ScannedFile};
+
+const STORAGE_KINDS: [&str; 3] = ["persistent", "instance", "temporary"];
+const WRITE_METHODS: [&str; 3] = ["set", "update", "remove"];
+
+/// Method-name prefixes treated as read-only/pure and skipped even though
+/// they may touch storage accessors in passing.
+const DEFAULT_READ_ONLY_PREFIXES: [&str; 3] = ["get", "is", "view"];
+
+pub struct EventCoverageRule {
+    read_only_prefixes: Vec<&'static str>,
+}
+
+impl Default for EventCoverageRule {
+    fn default() -> Self {
+        EventCoverageRule {
+            read_only_prefixes: DEFAULT_READ_ONLY_PREFIXES.to_vec(),
+        }
+    }
+}
+
+impl EventCoverageRule {
+    /// Build the rule with a custom set of read-only/pure method-name
+    /// prefixes, replacing the default `get`/`is`/`view` list.
+    pub fn new(read_only_prefixes: Vec<&'static str>) -> Self {
+        EventCoverageRule { read_only_prefixes }
+    }
+}
+
+impl super::Rule for EventCoverageRule {
+    fn name(&self) -> &'static str {
+        "missing_event_emission"
+    }
+
+    fn check(&self, file: &ScannedFile) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for block in find_attr_impl_blocks(&file.text, "contractimpl") {
+            for method in &block.methods {
+                if self
+                    .read_only_prefixes
+                    .iter()
+                    .any(|prefix| method.name.starts_with(prefix))
+                {
+                    continue;
+                }
+                let writes = find_storage_writes(&method.body, &STORAGE_KINDS, &WRITE_METHODS);
+                if writes.is_empty() || method.body.contains("events().publish(") {
+                    continue;
+                }
+                let keys: Vec<&str> = writes.iter().map(|w| w.key_expr.as_str()).collect();
+                findings.push(Finding {
+                    rule: self.name(),
+                    severity: Severity::Warning,
+                    message: format!(
+                        "`{}::{}` mutates storage ({}) but never calls \
+                         `env.events().publish(...)` — indexers and other off-chain observers \
+                         can't see this state change",
+                        block.type_name,
+                        method.name,
+                        keys.join(", "),
+                    ),
+                    span: Span {
+                        file: file.path.clone(),
+                        line: file.line_at(method.sig_start),
+                    },
+                });
+            }
+        }
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rule;
+    use crate::scan::ScannedFile;
+
+    fn scan(text: &str) -> ScannedFile {
+        ScannedFile {
+            path: "contract.rs".into(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn mutation_without_an_event_is_flagged() {
+        let file = scan(
+            r#"
+            #[contractimpl]
+            impl Counter {
+                pub fn inc(env: Env, who: Address, by: i32) -> i32 {
+                    let key = (Symbol::new(&env, "count"), who);
+                    env.storage().persistent().set(&key, &by);
+                    by
+                }
+            }
+            "#,
+        );
+        let findings = EventCoverageRule::default().check(&file);
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn mutation_with_an_event_is_not_flagged() {
+        let file = scan(
+            r#"
+            #[contractimpl]
+            impl Counter {
+                pub fn inc(env: Env, who: Address, by: i32) -> i32 {
+                    let key = (Symbol::new(&env, "count"), who);
+                    env.storage().persistent().set(&key, &by);
+                    env.events().publish((Symbol::new(&env, "inc"),), by);
+                    by
+                }
+            }
+            "#,
+        );
+        assert!(EventCoverageRule::default().check(&file).is_empty());
+    }
+
+    #[test]
+    fn read_only_prefix_is_skipped_even_without_an_event() {
+        let file = scan(
+            r#"
+            #[contractimpl]
+            impl Counter {
+                pub fn get(env: Env, who: Address) -> i32 {
+                    env.storage().persistent().set(&who, &0);
+                    0
+                }
+            }
+            "#,
+        );
+        assert!(EventCoverageRule::default().check(&file).is_empty());
+    }
+
+    #[test]
+    fn custom_read_only_prefixes_replace_the_default_list() {
+        let file = scan(
+            r#"
+            #[contractimpl]
+            impl Counter {
+                pub fn fetch(env: Env, who: Address) -> i32 {
+                    env.storage().persistent().set(&who, &0);
+                    0
+                }
+            }
+            "#,
+        );
+        let rule = EventCoverageRule::new(vec!["fetch"]);
+        assert!(rule.check(&file).is_empty());
+
+        // `get` is a default prefix, but no longer skipped once replaced.
+        let getter = scan(
+            r#"
+            #[contractimpl]
+            impl Counter {
+                pub fn get(env: Env, who: Address) -> i32 {
+                    env.storage().persistent().set(&who, &0);
+                    0
+                }
+            }
+            "#,
+        );
+        assert_eq!(rule.check(&getter).len(), 1);
+    }
+}
@@ -0,0 +1,29 @@
+//! Audit rules. Each rule inspects a [`ScannedFile`](crate::scan::ScannedFile)
+//! and returns zero or more [`Finding`](crate::model::Finding)s; [`all_rules`]
+//! is the registry the analysis pass runs over every file in the matrix.
+
+mod event_coverage;
+mod require_auth;
+mod storage_ttl;
+mod unchecked_arithmetic;
+mod unsafe_shared_state;
+
+pub use event_coverage::EventCoverageRule;
+
+use crate::model::Finding;
+use crate::scan::ScannedFile;
+
+pub trait Rule {
+    fn name(&self) -> &'static str;
+    fn check(&self, file: &ScannedFile) -> Vec<Finding>;
+}
+
+pub fn all_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(require_auth::RequireAuthRule),
+        Box::new(unchecked_arithmetic::UncheckedArithmeticRule),
+        Box::new(unsafe_shared_state::UnsafeSharedStateRule),
+        Box::new(storage_ttl::StorageTtlRule),
+        Box::new(event_coverage::EventCoverageRule::default()),
+    ]
+}
@@ -0,0 +1,195 @@
+//! Flags Soroban contract methods that take an `Address` and read or write
+//! storage without requiring that address's authorization first — the
+//! canonical Soroban "missing `require_auth`" bug. Which key the storage
+//! call uses is not checked.
+
+use crate::model::{Finding, Severity, Span};
+use crate::scan::{find_attr_impl_blocks, match_paren, ContractFn, ScannedFile};
+
+pub struct RequireAuthRule;
+
+const STORAGE_KINDS: [&str; 3] = ["persistent()", "instance()", "temporary()"];
+const WRITE_METHODS: [&str; 3] = [".set(", ".update(", ".remove("];
+
+impl super::Rule for RequireAuthRule {
+    fn name(&self) -> &'static str {
+        "require_auth_omission"
+    }
+
+    fn check(&self, file: &ScannedFile) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for block in find_attr_impl_blocks(&file.text, "contractimpl") {
+            for method in &block.methods {
+                let addresses: Vec<&str> = method
+                    .params
+                    .iter()
+                    .filter(|p| p.ty == "Address")
+                    .map(|p| p.name.as_str())
+                    .collect();
+                if addresses.is_empty() {
+                    continue;
+                }
+                let writes_storage = writes_storage(method);
+                if !writes_storage && !STORAGE_KINDS.iter().any(|kind| method.body.contains(kind)) {
+                    continue;
+                }
+                for addr in &addresses {
+                    if requires_auth(method, addr) {
+                        continue;
+                    }
+                    let severity = if writes_storage {
+                        Severity::Critical
+                    } else {
+                        Severity::Informational
+                    };
+                    let action = if writes_storage {
+                        "writing to storage"
+                    } else {
+                        "reading storage"
+                    };
+                    findings.push(Finding {
+                        rule: self.name(),
+                        severity,
+                        message: format!(
+                            "`{}::{}` takes `{addr}: Address` but never calls \
+                             `{addr}.require_auth()` (or `require_auth_for_args`) before {action}",
+                            block.type_name, method.name,
+                        ),
+                        span: Span {
+                            file: file.path.clone(),
+                            line: file.line_at(method.sig_start),
+                        },
+                    });
+                }
+            }
+        }
+        findings
+    }
+}
+
+fn writes_storage(method: &ContractFn) -> bool {
+    STORAGE_KINDS.iter().any(|kind| {
+        method.body.contains(kind) && WRITE_METHODS.iter().any(|w| method.body.contains(w))
+    })
+}
+
+/// Whether `addr` (or `addr.clone()`, which is still the same principal) is
+/// passed to `require_auth`/`require_auth_for_args` anywhere in the body.
+fn requires_auth(method: &ContractFn, addr: &str) -> bool {
+    let direct = format!("{addr}.require_auth(");
+    let via_clone = format!("{addr}.clone().require_auth(");
+    if method.body.contains(&direct) || method.body.contains(&via_clone) {
+        return true;
+    }
+    find_call_args(&method.body, "require_auth_for_args")
+        .iter()
+        .any(|args| call_args_mention(args, addr))
+}
+
+/// Extract the argument lists of every `name(...)` call in `text`.
+fn find_call_args<'a>(text: &'a str, name: &str) -> Vec<&'a str> {
+    let mut calls = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = text[search_from..].find(name) {
+        let paren_idx = search_from + rel + name.len();
+        if text.as_bytes().get(paren_idx) == Some(&b'(') {
+            if let Some(end) = match_paren(text, paren_idx) {
+                calls.push(&text[paren_idx + 1..end]);
+                search_from = end + 1;
+                continue;
+            }
+        }
+        search_from = paren_idx;
+    }
+    calls
+}
+
+fn call_args_mention(args: &str, addr: &str) -> bool {
+    args.split(',').any(|a| {
+        let a = a.trim();
+        a == addr || a == format!("&{addr}") || a == format!("{addr}.clone()")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rule;
+
+    fn scan(text: &str) -> ScannedFile {
+        ScannedFile {
+            path: "contract.rs".into(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn missing_auth_on_write_is_critical() {
+        let file = scan(
+            r#"
+            #[contractimpl]
+            impl Counter {
+                pub fn inc(env: Env, who: Address, by: i32) -> i32 {
+                    let next = 1;
+                    env.storage().persistent().set(&who, &next);
+                    next
+                }
+            }
+            "#,
+        );
+        let findings = RequireAuthRule.check(&file);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Critical);
+    }
+
+    #[test]
+    fn missing_auth_on_read_only_is_informational() {
+        let file = scan(
+            r#"
+            #[contractimpl]
+            impl Counter {
+                pub fn get(env: Env, who: Address) -> i32 {
+                    env.storage().persistent().get(&who).unwrap_or(0)
+                }
+            }
+            "#,
+        );
+        let findings = RequireAuthRule.check(&file);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Informational);
+    }
+
+    #[test]
+    fn require_auth_via_clone_suppresses_the_finding() {
+        let file = scan(
+            r#"
+            #[contractimpl]
+            impl Counter {
+                pub fn inc(env: Env, who: Address, by: i32) -> i32 {
+                    who.clone().require_auth();
+                    env.storage().persistent().set(&who, &by);
+                    by
+                }
+            }
+            "#,
+        );
+        assert!(RequireAuthRule.check(&file).is_empty());
+    }
+
+    #[test]
+    fn require_auth_for_args_mentioning_the_address_suppresses_the_finding() {
+        let file = scan(
+            r#"
+            #[contractimpl]
+            impl Counter {
+                pub fn inc(env: Env, who: Address, by: i32) -> i32 {
+                    who.require_auth_for_args(who.clone(), by);
+                    env.storage().persistent().set(&who, &by);
+                    by
+                }
+            }
+            "#,
+        );
+        assert!(RequireAuthRule.check(&file).is_empty());
+    }
+}
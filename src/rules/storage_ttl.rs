@@ -0,0 +1,156 @@
+//! Flags Soroban storage entries that are written but whose lifetime is
+//! never extended. Persistent and instance storage expire their entries
+//! unless a code path calls `extend_ttl`/`bump` for that storage kind, so
+//! a `set` with no matching extension anywhere in the contract is a
+//! data-loss risk: the entry can vanish out from under the contract.
+
+use crate::model::{Finding, Severity, Span};
+use crate::scan::{find_attr_impl_blocks, find_storage_writes, ContractFn, ScannedFile};
+
+pub struct StorageTtlRule;
+
+const STORAGE_KINDS: [&str; 2] = ["persistent", "instance"];
+const WRITE_METHODS: [&str; 2] = ["set", "update"];
+const EXTEND_METHODS: [&str; 2] = ["extend_ttl", "bump"];
+
+impl super::Rule for StorageTtlRule {
+    fn name(&self) -> &'static str {
+        "storage_ttl_expiration"
+    }
+
+    fn check(&self, file: &ScannedFile) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let impl_blocks = find_attr_impl_blocks(&file.text, "contractimpl");
+        for contract_type in find_contract_types(&file.text) {
+            let Some(block) = impl_blocks.iter().find(|b| b.type_name == contract_type) else {
+                continue;
+            };
+
+            let mut extended_kinds = std::collections::HashSet::new();
+            for method in &block.methods {
+                extended_kinds.extend(extended_storage_kinds(method));
+            }
+
+            for method in &block.methods {
+                for write in find_storage_writes(&method.body, &STORAGE_KINDS, &WRITE_METHODS) {
+                    if extended_kinds.contains(write.kind.as_str()) {
+                        continue;
+                    }
+                    findings.push(Finding {
+                        rule: self.name(),
+                        severity: Severity::Warning,
+                        message: format!(
+                            "`{}::{}` writes `{}` storage entry `{}` but no method on `{}` ever \
+                             calls `{}().extend_ttl()`/`bump()` — the entry can expire and be \
+                             lost",
+                            contract_type, method.name, write.kind, write.key_expr, contract_type,
+                            write.kind,
+                        ),
+                        span: Span {
+                            file: file.path.clone(),
+                            line: file.line_at(method.body_start + write.offset),
+                        },
+                    });
+                }
+            }
+        }
+        findings
+    }
+}
+
+/// Names of every `#[contract] pub struct Name;` in `text`.
+fn find_contract_types(text: &str) -> Vec<String> {
+    let marker = "#[contract]";
+    let mut names = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = text[search_from..].find(marker) {
+        let after = search_from + rel + marker.len();
+        search_from = after;
+        let Some(struct_rel) = text[after..].find("struct ") else {
+            continue;
+        };
+        let name_start = after + struct_rel + "struct ".len();
+        let end = text[name_start..]
+            .find(|c: char| c == ';' || c == '{' || c.is_whitespace())
+            .map(|i| name_start + i)
+            .unwrap_or(text.len());
+        names.push(text[name_start..end].to_string());
+    }
+    names
+}
+
+/// Storage kinds for which `method`'s body calls `extend_ttl`/`bump`.
+fn extended_storage_kinds(method: &ContractFn) -> Vec<&'static str> {
+    STORAGE_KINDS
+        .into_iter()
+        .filter(|kind| {
+            let marker = format!("{kind}().");
+            method.body.match_indices(&marker).any(|(idx, _)| {
+                let after = idx + marker.len();
+                EXTEND_METHODS
+                    .iter()
+                    .any(|m| method.body[after..].starts_with(m))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rule;
+
+    fn scan(text: &str) -> ScannedFile {
+        ScannedFile {
+            path: "contract.rs".into(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn write_with_no_extend_anywhere_is_flagged() {
+        let file = scan(
+            r#"
+            #[contract]
+            pub struct Counter;
+
+            #[contractimpl]
+            impl Counter {
+                pub fn inc(env: Env, who: Address, by: i32) -> i32 {
+                    let key = (Symbol::new(&env, "count"), who);
+                    env.storage().persistent().set(&key, &by);
+                    by
+                }
+            }
+            "#,
+        );
+        let findings = StorageTtlRule.check(&file);
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("persistent"));
+    }
+
+    #[test]
+    fn write_with_extend_on_another_method_of_the_same_contract_is_not_flagged() {
+        let file = scan(
+            r#"
+            #[contract]
+            pub struct Counter;
+
+            #[contractimpl]
+            impl Counter {
+                pub fn inc(env: Env, who: Address, by: i32) -> i32 {
+                    let key = (Symbol::new(&env, "count"), who);
+                    env.storage().persistent().set(&key, &by);
+                    by
+                }
+
+                pub fn keep_alive(env: Env, who: Address) {
+                    let key = (Symbol::new(&env, "count"), who);
+                    env.storage().persistent().extend_ttl(&key, 100, 200);
+                }
+            }
+            "#,
+        );
+        assert!(StorageTtlRule.check(&file).is_empty());
+    }
+}
@@ -0,0 +1,362 @@
+//! Flags raw `+`, `-`, and `*` on integer-typed operands inside contract
+//! functions. Release WASM builds wrap silently on overflow, so these
+//! sites should use `checked_*`/`saturating_*` arithmetic or widen to
+//! `i128` instead.
+
+use std::collections::HashMap;
+
+use crate::model::{Finding, Severity, Span};
+use crate::scan::{find_impl_blocks, match_brace, split_top_level, ContractFn, ScannedFile};
+
+pub struct UncheckedArithmeticRule;
+
+const INT_TYPES: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// Comment maintainers can put on the offending line to suppress a finding
+/// for a known-safe site, e.g. `let n = a + b; // uatu:allow(overflow)`.
+const ALLOW_COMMENT: &str = "uatu:allow(overflow)";
+
+impl super::Rule for UncheckedArithmeticRule {
+    fn name(&self) -> &'static str {
+        "unchecked_arithmetic"
+    }
+
+    fn check(&self, file: &ScannedFile) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for block in find_impl_blocks(&file.text) {
+            let fields = struct_field_types(&file.text, &block.type_name);
+            for method in &block.methods {
+                let locals = local_int_types(method);
+                for site in find_arithmetic_sites(&method.body) {
+                    if site.allowed {
+                        continue;
+                    }
+                    if !is_integer_operand(&site.lhs, &locals, &fields)
+                        || !is_integer_operand(&site.rhs, &locals, &fields)
+                    {
+                        continue;
+                    }
+                    let op_name = op_name(site.op);
+                    findings.push(Finding {
+                        rule: self.name(),
+                        severity: if site.stored {
+                            Severity::Critical
+                        } else {
+                            Severity::Warning
+                        },
+                        message: format!(
+                            "`{}::{}` computes `{} {} {}` with raw integer arithmetic, which wraps \
+                             silently on overflow in release WASM builds; use `checked_{op_name}`/\
+                             `saturating_{op_name}` or widen to `i128` (suppress a known-safe site \
+                             with `// {ALLOW_COMMENT}`)",
+                            block.type_name, method.name, site.lhs, site.op, site.rhs,
+                        ),
+                        span: Span {
+                            file: file.path.clone(),
+                            line: file.line_at(method.body_start + site.line_offset),
+                        },
+                    });
+                }
+            }
+        }
+        findings
+    }
+}
+
+fn op_name(op: char) -> &'static str {
+    match op {
+        '+' => "add",
+        '-' => "sub",
+        '*' => "mul",
+        _ => "op",
+    }
+}
+
+struct ArithmeticSite {
+    op: char,
+    lhs: String,
+    rhs: String,
+    /// The result feeds a storage write or a struct field directly, as
+    /// opposed to being merely returned or used in a local computation.
+    stored: bool,
+    /// Byte offset of the containing line, relative to the function body.
+    line_offset: usize,
+    allowed: bool,
+}
+
+fn find_arithmetic_sites(body: &str) -> Vec<ArithmeticSite> {
+    let mut sites = Vec::new();
+    let mut line_offset = 0usize;
+    for raw_line in body.split_inclusive('\n') {
+        let this_line_offset = line_offset;
+        line_offset += raw_line.len();
+
+        let allowed = raw_line.contains(ALLOW_COMMENT);
+        let code = raw_line.split("//").next().unwrap_or(raw_line);
+        let tokens: Vec<&str> = code.split_whitespace().collect();
+        let let_name = let_binding_name(&tokens);
+        let assigns_field = tokens.get(1) == Some(&"=")
+            && tokens.first().is_some_and(|t| t.starts_with("self."));
+
+        let mut i = 0;
+        while i < tokens.len() {
+            let tok = tokens[i];
+            if let Some(op) = compound_assign_op(tok) {
+                if i > 0 {
+                    let target = trim_operand(tokens[i - 1]);
+                    let rhs = tokens.get(i + 1).map(|t| trim_operand(t)).unwrap_or_default();
+                    sites.push(ArithmeticSite {
+                        op,
+                        lhs: target.clone(),
+                        rhs,
+                        stored: target.starts_with("self."),
+                        line_offset: this_line_offset,
+                        allowed,
+                    });
+                }
+            } else if let Some(op) = binary_op(tok) {
+                if i > 0 && i + 1 < tokens.len() {
+                    let lhs = trim_operand(tokens[i - 1]);
+                    let rhs = trim_operand(tokens[i + 1]);
+                    sites.push(ArithmeticSite {
+                        op,
+                        lhs,
+                        rhs,
+                        stored: assigns_field
+                            || let_name
+                                .as_ref()
+                                .map(|name| is_stored(body, name))
+                                .unwrap_or(false),
+                        line_offset: this_line_offset,
+                        allowed,
+                    });
+                }
+            }
+            i += 1;
+        }
+    }
+    sites
+}
+
+fn compound_assign_op(tok: &str) -> Option<char> {
+    match tok {
+        "+=" => Some('+'),
+        "-=" => Some('-'),
+        "*=" => Some('*'),
+        _ => None,
+    }
+}
+
+fn binary_op(tok: &str) -> Option<char> {
+    match tok {
+        "+" => Some('+'),
+        "-" => Some('-'),
+        "*" => Some('*'),
+        _ => None,
+    }
+}
+
+fn trim_operand(tok: &str) -> String {
+    tok.trim_matches(|c: char| c == ';' || c == ',' || c == ')' || c == '(')
+        .to_string()
+}
+
+/// If `tokens` is a `let [mut] <name>[: <ty>] = ...` statement, the bound
+/// variable name.
+fn let_binding_name(tokens: &[&str]) -> Option<String> {
+    if tokens.first() != Some(&"let") {
+        return None;
+    }
+    let name_tok = if tokens.get(1) == Some(&"mut") {
+        tokens.get(2)?
+    } else {
+        tokens.get(1)?
+    };
+    Some(name_tok.trim_end_matches(':').to_string())
+}
+
+/// Whether `name` is later written into storage (`.set(...)`) anywhere in
+/// the function body — a rough but effective proxy for "this value is
+/// persisted", since the repo's fixtures always do so on the same line.
+fn is_stored(body: &str, name: &str) -> bool {
+    body.lines().any(|l| l.contains(".set(") && l.contains(name))
+}
+
+fn is_integer_operand(
+    operand: &str,
+    locals: &HashMap<String, String>,
+    fields: &HashMap<String, String>,
+) -> bool {
+    if operand.is_empty() {
+        return false;
+    }
+    if operand.trim_start_matches('-').chars().all(|c| c.is_ascii_digit()) {
+        return true;
+    }
+    if let Some(field) = operand.strip_prefix("self.") {
+        return fields
+            .get(field)
+            .is_some_and(|ty| INT_TYPES.contains(&ty.as_str()));
+    }
+    locals
+        .get(operand)
+        .is_some_and(|ty| INT_TYPES.contains(&ty.as_str()))
+}
+
+/// Resolve `type_name`'s declared field types from its `struct { .. }`
+/// definition, the same way [`local_int_types`] resolves `let` bindings —
+/// so `self.<field>` is only treated as integer-typed when the field
+/// actually is, instead of being assumed unconditionally.
+fn struct_field_types(text: &str, type_name: &str) -> HashMap<String, String> {
+    let mut fields = HashMap::new();
+    let marker = format!("struct {type_name}");
+    let mut search_from = 0;
+    while let Some(rel) = text[search_from..].find(&marker) {
+        let marker_idx = search_from + rel;
+        let after = marker_idx + marker.len();
+        search_from = after;
+        // Reject partial-name matches, e.g. `struct CounterV2` for `Counter`.
+        if text.as_bytes().get(after).is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'_') {
+            continue;
+        }
+        let Some(brace_rel) = text[after..].find(['{', ';']) else {
+            continue;
+        };
+        let brace_idx = after + brace_rel;
+        if text.as_bytes()[brace_idx] != b'{' {
+            // Unit or tuple struct: no named fields to resolve.
+            return fields;
+        }
+        let Some(end) = match_brace(text, brace_idx) else {
+            continue;
+        };
+        for field in split_top_level(&text[brace_idx + 1..end], ',') {
+            let field = field.trim().trim_start_matches("pub ").trim();
+            if let Some((name, ty)) = field.split_once(':') {
+                fields.insert(name.trim().to_string(), ty.trim().to_string());
+            }
+        }
+        return fields;
+    }
+    fields
+}
+
+/// Map each locally-known name to its declared type: function parameters
+/// plus explicitly-annotated `let` bindings (`let current: i32 = ...`).
+/// Non-integer types are kept too; [`is_integer_operand`] filters them.
+fn local_int_types(method: &ContractFn) -> HashMap<String, String> {
+    let mut locals: HashMap<String, String> = method
+        .params
+        .iter()
+        .map(|p| (p.name.clone(), p.ty.clone()))
+        .collect();
+    for raw_line in method.body.lines() {
+        let code = raw_line.split("//").next().unwrap_or(raw_line);
+        let tokens: Vec<&str> = code.split_whitespace().collect();
+        if tokens.first() != Some(&"let") {
+            continue;
+        }
+        let name_idx = if tokens.get(1) == Some(&"mut") { 2 } else { 1 };
+        let Some(name_tok) = tokens.get(name_idx) else {
+            continue;
+        };
+        // `let current: i32 = ...` tokenizes as ["let", "current:", "i32", "=", ...].
+        if let Some(name) = name_tok.strip_suffix(':') {
+            if let Some(ty) = tokens.get(name_idx + 1) {
+                locals.insert(name.to_string(), (*ty).to_string());
+            }
+        }
+    }
+    locals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rule;
+
+    fn scan(text: &str) -> ScannedFile {
+        ScannedFile {
+            path: "contract.rs".into(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn compound_assign_on_an_integer_field_is_flagged_as_critical() {
+        let file = scan(
+            r#"
+            pub struct Counter {
+                value: i32,
+            }
+
+            impl Counter {
+                pub fn increment(&mut self) {
+                    self.value += 1;
+                }
+            }
+            "#,
+        );
+        let findings = UncheckedArithmeticRule.check(&file);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Critical);
+    }
+
+    #[test]
+    fn string_concatenation_on_a_non_integer_field_is_not_flagged() {
+        let file = scan(
+            r#"
+            pub struct Greeter {
+                name: String,
+            }
+
+            impl Greeter {
+                pub fn greet(&self, suffix: String) -> String {
+                    self.name + suffix
+                }
+            }
+            "#,
+        );
+        assert!(UncheckedArithmeticRule.check(&file).is_empty());
+    }
+
+    #[test]
+    fn allow_comment_suppresses_the_finding() {
+        let file = scan(
+            r#"
+            pub struct Counter {
+                value: i32,
+            }
+
+            impl Counter {
+                pub fn increment(&mut self) {
+                    self.value = self.value + 1; // uatu:allow(overflow)
+                }
+            }
+            "#,
+        );
+        assert!(UncheckedArithmeticRule.check(&file).is_empty());
+    }
+
+    #[test]
+    fn compound_assign_of_a_param_into_an_integer_field_is_critical_severity() {
+        let file = scan(
+            r#"
+            pub struct Counter {
+                value: i32,
+            }
+
+            impl Counter {
+                pub fn inc(&mut self, by: i32) {
+                    self.value += by;
+                }
+            }
+            "#,
+        );
+        let findings = UncheckedArithmeticRule.check(&file);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Critical);
+    }
+}
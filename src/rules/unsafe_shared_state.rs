@@ -0,0 +1,156 @@
+//! Flags `static mut` globals read or written through `unsafe` blocks.
+//! Mutable process-global state isn't thread-safe, whether it backs a
+//! plain Rust module or (less obviously) a contract built for a
+//! single-threaded WASM host today that may not stay that way.
+
+use crate::model::{Finding, Severity, Span};
+use crate::scan::{contains_ident, match_brace, ScannedFile};
+
+pub struct UnsafeSharedStateRule;
+
+impl super::Rule for UnsafeSharedStateRule {
+    fn name(&self) -> &'static str {
+        "unsafe_shared_state"
+    }
+
+    fn check(&self, file: &ScannedFile) -> Vec<Finding> {
+        let globals = find_static_mut_globals(&file.text);
+        if globals.is_empty() {
+            return Vec::new();
+        }
+        let mut findings = Vec::new();
+        for block in find_unsafe_blocks(&file.text) {
+            let touched: Vec<&str> = globals
+                .iter()
+                .filter(|g| contains_ident(&block.body, g))
+                .map(|g| g.as_str())
+                .collect();
+            if touched.is_empty() {
+                continue;
+            }
+            findings.push(Finding {
+                rule: self.name(),
+                severity: Severity::Warning,
+                message: format!(
+                    "`unsafe` block reads or writes shared global state ({}); `static mut` is \
+                     not thread-safe — prefer `Cell`/`AtomicU32`-style interior mutability, or \
+                     Soroban contract storage, instead",
+                    touched.join(", "),
+                ),
+                span: Span {
+                    file: file.path.clone(),
+                    line: file.line_at(block.start),
+                },
+            });
+        }
+        findings
+    }
+}
+
+/// Name of every `static mut NAME` declaration in `text`.
+fn find_static_mut_globals(text: &str) -> Vec<String> {
+    let marker = "static mut ";
+    let mut globals = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = text[search_from..].find(marker) {
+        let name_start = search_from + rel + marker.len();
+        let end = text[name_start..]
+            .find(|c: char| c == ':' || c == '=' || c.is_whitespace())
+            .map(|i| name_start + i)
+            .unwrap_or(text.len());
+        globals.push(text[name_start..end].to_string());
+        search_from = end;
+    }
+    globals
+}
+
+struct UnsafeBlock {
+    body: String,
+    start: usize,
+}
+
+/// Every `unsafe { ... }` block in `text` (not `unsafe fn`, which has no
+/// block of its own to inspect here).
+fn find_unsafe_blocks(text: &str) -> Vec<UnsafeBlock> {
+    let marker = "unsafe";
+    let mut blocks = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = text[search_from..].find(marker) {
+        let kw_start = search_from + rel;
+        let after = kw_start + marker.len();
+        search_from = after;
+        let Some(brace_rel) = text[after..].find(|c: char| !c.is_whitespace()) else {
+            continue;
+        };
+        let brace_idx = after + brace_rel;
+        if text.as_bytes().get(brace_idx) != Some(&b'{') {
+            continue;
+        }
+        let Some(end) = match_brace(text, brace_idx) else {
+            continue;
+        };
+        blocks.push(UnsafeBlock {
+            body: text[brace_idx..=end].to_string(),
+            start: kw_start,
+        });
+        search_from = end + 1;
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rule;
+
+    fn scan(text: &str) -> ScannedFile {
+        ScannedFile {
+            path: "module.rs".into(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn unsafe_block_touching_a_static_mut_is_flagged() {
+        let file = scan(
+            r#"
+            pub mod counter {
+                static mut COUNT: u32 = 0;
+
+                pub fn ping(n: u32) -> u32 {
+                    unsafe { COUNT += n; COUNT }
+                }
+            }
+            "#,
+        );
+        let findings = UnsafeSharedStateRule.check(&file);
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("COUNT"));
+    }
+
+    #[test]
+    fn unsafe_block_not_touching_any_global_is_not_flagged() {
+        let file = scan(
+            r#"
+            static mut COUNT: u32 = 0;
+
+            pub fn raw_deref(p: *const u32) -> u32 {
+                unsafe { *p }
+            }
+            "#,
+        );
+        assert!(UnsafeSharedStateRule.check(&file).is_empty());
+    }
+
+    #[test]
+    fn no_static_mut_globals_means_no_findings_even_with_unsafe() {
+        let file = scan(
+            r#"
+            pub fn raw_deref(p: *const u32) -> u32 {
+                unsafe { *p }
+            }
+            "#,
+        );
+        assert!(UnsafeSharedStateRule.check(&file).is_empty());
+    }
+}
@@ -0,0 +1,373 @@
+//! Lightweight source scanning shared by every audit rule.
+//!
+//! UATU does not carry a full parser for the contract fixtures it audits;
+//! instead each rule works against brace-matched text spans, which is
+//! enough to reliably find `#[contractimpl]` blocks, their public methods,
+//! and the storage/auth calls inside them.
+
+use std::path::PathBuf;
+
+/// Source text for one file under audit, kept alongside its path so
+/// findings can point back at it.
+#[derive(Debug, Clone)]
+pub struct ScannedFile {
+    pub path: PathBuf,
+    pub text: String,
+}
+
+impl ScannedFile {
+    pub fn read(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let path = path.into();
+        let text = std::fs::read_to_string(&path)?;
+        Ok(ScannedFile { path, text })
+    }
+
+    /// 1-based line number for a byte offset into `self.text`.
+    pub fn line_at(&self, byte_offset: usize) -> usize {
+        self.text[..byte_offset.min(self.text.len())]
+            .bytes()
+            .filter(|&b| b == b'\n')
+            .count()
+            + 1
+    }
+}
+
+/// One `pub fn` found inside an `impl` or `mod` block.
+#[derive(Debug, Clone)]
+pub struct ContractFn {
+    pub name: String,
+    pub params: Vec<Param>,
+    /// Byte offset of the `pub fn` keyword, in the original file.
+    pub sig_start: usize,
+    /// Byte offset of the function body's opening brace, in the original
+    /// file; lets rules translate offsets inside `body` into real spans.
+    pub body_start: usize,
+    /// Full function body, braces included.
+    pub body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Param {
+    pub name: String,
+    pub ty: String,
+}
+
+/// One `impl` block tagged with `#[attr]` (e.g. `"contractimpl"`).
+#[derive(Debug, Clone)]
+pub struct AttrImplBlock {
+    pub type_name: String,
+    pub methods: Vec<ContractFn>,
+}
+
+/// Find every `impl` block immediately preceded by `#[attr]` and collect
+/// its public methods.
+pub fn find_attr_impl_blocks(text: &str, attr: &str) -> Vec<AttrImplBlock> {
+    let marker = format!("#[{attr}]");
+    let mut blocks = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = text[search_from..].find(&marker) {
+        let marker_idx = search_from + rel;
+        let after_marker = marker_idx + marker.len();
+        search_from = after_marker;
+        let Some(impl_idx_rel) = text[after_marker..].find("impl ") else {
+            continue;
+        };
+        let impl_idx = after_marker + impl_idx_rel;
+        let Some(brace_idx_rel) = text[impl_idx..].find('{') else {
+            continue;
+        };
+        let brace_idx = impl_idx + brace_idx_rel;
+        let header = text[impl_idx + "impl ".len()..brace_idx].trim();
+        let type_name = header.split_whitespace().next().unwrap_or("").to_string();
+        let Some(end) = match_brace(text, brace_idx) else {
+            continue;
+        };
+        let methods = find_pub_fns(&text[brace_idx + 1..end], brace_idx + 1);
+        blocks.push(AttrImplBlock { type_name, methods });
+        search_from = end + 1;
+    }
+    blocks
+}
+
+/// Find every `pub fn` directly inside `text`, where `text` starts at byte
+/// offset `base_offset` in the original file (used to compute line numbers
+/// from the spans we record).
+pub fn find_pub_fns(text: &str, base_offset: usize) -> Vec<ContractFn> {
+    let mut fns = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = text[search_from..].find("pub fn ") {
+        let sig_start = search_from + rel;
+        let name_start = sig_start + "pub fn ".len();
+        let Some(paren_start) = text[name_start..].find('(').map(|i| name_start + i) else {
+            break;
+        };
+        let name = text[name_start..paren_start].trim().to_string();
+        let Some(paren_end) = match_paren(text, paren_start) else {
+            break;
+        };
+        let params = parse_params(&text[paren_start + 1..paren_end]);
+        let Some(brace_start) = text[paren_end..].find('{').map(|i| paren_end + i) else {
+            break;
+        };
+        let Some(body_end) = match_brace(text, brace_start) else {
+            break;
+        };
+        fns.push(ContractFn {
+            name,
+            params,
+            sig_start: base_offset + sig_start,
+            body_start: base_offset + brace_start,
+            body: text[brace_start..=body_end].to_string(),
+        });
+        search_from = body_end + 1;
+    }
+    fns
+}
+
+/// One (optionally `pub`) `mod name { ... }` block and its public
+/// functions.
+#[derive(Debug, Clone)]
+pub struct ModBlock {
+    pub name: String,
+    pub functions: Vec<ContractFn>,
+}
+
+/// Find every `mod name { ... }` block in `text` and collect its public
+/// functions — lets callers resolve direct `module::function` calls the
+/// same way they resolve contract methods.
+pub fn find_mod_blocks(text: &str) -> Vec<ModBlock> {
+    let mut blocks = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = text[search_from..].find("mod ") {
+        let mod_idx = search_from + rel;
+        let preceded_by_ident = mod_idx > 0 && {
+            let prev = text.as_bytes()[mod_idx - 1];
+            prev.is_ascii_alphanumeric() || prev == b'_'
+        };
+        if preceded_by_ident {
+            search_from = mod_idx + "mod ".len();
+            continue;
+        }
+        let name_start = mod_idx + "mod ".len();
+        let Some(delim_rel) = text[name_start..].find(['{', ';']) else {
+            break;
+        };
+        let delim_idx = name_start + delim_rel;
+        if text.as_bytes()[delim_idx] != b'{' {
+            // `mod foo;` — a separate file, nothing to scan here.
+            search_from = delim_idx + 1;
+            continue;
+        }
+        let name = text[name_start..delim_idx].trim().to_string();
+        let Some(end) = match_brace(text, delim_idx) else {
+            break;
+        };
+        let functions = find_pub_fns(&text[delim_idx + 1..end], delim_idx + 1);
+        blocks.push(ModBlock { name, functions });
+        search_from = end + 1;
+    }
+    blocks
+}
+
+/// Find every `impl TypeName { ... }` block in `text`, regardless of any
+/// attribute on it. Unlike [`find_attr_impl_blocks`], this also matches
+/// plain Rust `impl` blocks that aren't part of a Soroban contract.
+pub fn find_impl_blocks(text: &str) -> Vec<AttrImplBlock> {
+    let mut blocks = Vec::new();
+    let mut search_from = 0;
+    while let Some(rel) = text[search_from..].find("impl ") {
+        let impl_idx = search_from + rel;
+        let Some(brace_idx_rel) = text[impl_idx..].find('{') else {
+            break;
+        };
+        let brace_idx = impl_idx + brace_idx_rel;
+        let header = text[impl_idx + "impl ".len()..brace_idx].trim();
+        let type_name = header
+            .rsplit("for ")
+            .next()
+            .unwrap_or(header)
+            .split_whitespace()
+            .next()
+            .unwrap_or("")
+            .to_string();
+        let Some(end) = match_brace(text, brace_idx) else {
+            break;
+        };
+        let methods = find_pub_fns(&text[brace_idx + 1..end], brace_idx + 1);
+        blocks.push(AttrImplBlock { type_name, methods });
+        search_from = end + 1;
+    }
+    blocks
+}
+
+fn parse_params(raw: &str) -> Vec<Param> {
+    split_top_level(raw, ',')
+        .into_iter()
+        .filter_map(|p| {
+            let p = p.trim();
+            if p.is_empty() || p == "&self" || p == "self" || p == "&mut self" {
+                return None;
+            }
+            let (name, ty) = p.split_once(':')?;
+            Some(Param {
+                name: name.trim().to_string(),
+                ty: ty.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Split `s` on `sep`, ignoring separators nested inside `()`, `[]`, `{}`
+/// or `<>` (e.g. so `who: Map<Address, i32>` isn't split on its inner `,`).
+pub(crate) fn split_top_level(s: &str, sep: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut current = String::new();
+    for c in s.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth -= 1,
+            _ => {}
+        }
+        if c == sep && depth == 0 {
+            parts.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.trim().is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+fn match_delim(text: &str, open_idx: usize, open: u8, close: u8) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0i32;
+    for (i, &b) in bytes.iter().enumerate().skip(open_idx) {
+        if b == open {
+            depth += 1;
+        } else if b == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Byte index of the `}` matching the `{` at `open_idx`.
+pub fn match_brace(text: &str, open_idx: usize) -> Option<usize> {
+    match_delim(text, open_idx, b'{', b'}')
+}
+
+/// Byte index of the `)` matching the `(` at `open_idx`.
+pub fn match_paren(text: &str, open_idx: usize) -> Option<usize> {
+    match_delim(text, open_idx, b'(', b')')
+}
+
+/// One `<kind>().<write_method>(<key>, ...)` storage call found in a
+/// function body.
+#[derive(Debug, Clone)]
+pub struct StorageWrite {
+    pub kind: String,
+    pub key_expr: String,
+    pub offset: usize,
+}
+
+/// Find every call to any of `write_methods` on any of `kinds` (bare names
+/// such as `persistent`, `instance`, `temporary`, matched as
+/// `<kind>().<write_method>(`), returning the
+/// key-construction expression passed as the first argument. A bare local
+/// variable is resolved back through its `let` binding via
+/// [`resolve_let_bound_expr`] so callers see the real expression.
+pub fn find_storage_writes(body: &str, kinds: &[&str], write_methods: &[&str]) -> Vec<StorageWrite> {
+    let mut writes = Vec::new();
+    for kind in kinds {
+        let marker = format!("{kind}().");
+        let mut search_from = 0;
+        while let Some(rel) = body[search_from..].find(&marker) {
+            let call_start = search_from + rel + marker.len();
+            search_from = call_start;
+            let Some(write_method) = write_methods
+                .iter()
+                .find(|m| body[call_start..].starts_with(**m))
+            else {
+                continue;
+            };
+            let paren_idx = call_start + write_method.len();
+            if body.as_bytes().get(paren_idx) != Some(&b'(') {
+                continue;
+            }
+            let Some(end) = match_paren(body, paren_idx) else {
+                continue;
+            };
+            let args = &body[paren_idx + 1..end];
+            let key_arg = args
+                .split_once(',')
+                .map(|(k, _)| k)
+                .unwrap_or(args)
+                .trim()
+                .trim_start_matches('&');
+            writes.push(StorageWrite {
+                kind: (*kind).to_string(),
+                key_expr: resolve_let_bound_expr(body, key_arg),
+                offset: call_start,
+            });
+            search_from = end + 1;
+        }
+    }
+    writes
+}
+
+/// If `raw` is a bare identifier bound by a `let raw = <expr>;` earlier in
+/// `body`, returns that expression instead — so callers can show the real
+/// key-construction expression (e.g. the `(Symbol::new(..), who)` tuple)
+/// rather than just the local variable name.
+pub fn resolve_let_bound_expr(body: &str, raw: &str) -> String {
+    if raw.is_empty() || !raw.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return raw.to_string();
+    }
+    let marker = format!("let {raw} =");
+    let Some(rel) = body.find(&marker) else {
+        return raw.to_string();
+    };
+    let rhs_start = rel + marker.len();
+    let mut depth = 0i32;
+    for (i, c) in body[rhs_start..].char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ';' if depth == 0 => return body[rhs_start..rhs_start + i].trim().to_string(),
+            _ => {}
+        }
+    }
+    raw.to_string()
+}
+
+/// Whether `ident` appears in `text` as a whole identifier, not as part of
+/// a longer one — e.g. `contains_ident("COUNT += 1", "COUNT")` is true but
+/// `contains_ident("MY_COUNT", "COUNT")` is false.
+pub fn contains_ident(text: &str, ident: &str) -> bool {
+    if ident.is_empty() {
+        return false;
+    }
+    let bytes = text.as_bytes();
+    let mut start = 0;
+    while let Some(rel) = text[start..].find(ident) {
+        let idx = start + rel;
+        let before_ok = idx == 0 || !is_ident_byte(bytes[idx - 1]);
+        let after_idx = idx + ident.len();
+        let after_ok = after_idx >= bytes.len() || !is_ident_byte(bytes[after_idx]);
+        if before_ok && after_ok {
+            return true;
+        }
+        start = idx + 1;
+    }
+    false
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}